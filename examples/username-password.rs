@@ -1,10 +1,10 @@
 use terminal_prompt::Terminal;
 
-fn main() -> std::io::Result<()> {
+fn main() -> Result<(), Box<dyn std::error::Error>> {
 	let mut terminal = Terminal::open()?;
 	let username = terminal.prompt("Username: ")?;
 	let password = terminal.prompt_sensitive("Password: ")?;
 	println!("Username: {username}");
-	println!("Password: {password}");
+	println!("Password: {}", password.as_str());
 	Ok(())
 }
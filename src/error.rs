@@ -0,0 +1,39 @@
+/// An error returned by the high-level sensitive prompt functions.
+///
+/// Most failures are ordinary I/O errors ([`PromptError::Io`]). The [`PromptError::EnableFailed`]
+/// variant is reserved for the serious case where the response was read successfully but the
+/// terminal could not be returned to its previous mode afterwards, leaving it in a potentially
+/// broken state (for example with echoing still disabled). Callers may want to react to that
+/// specifically, e.g. by warning the user.
+#[derive(Debug)]
+pub enum PromptError {
+	/// An I/O error occurred while prompting.
+	Io(std::io::Error),
+
+	/// The response was read, but the terminal mode could not be restored afterwards.
+	EnableFailed(std::io::Error),
+}
+
+impl std::fmt::Display for PromptError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Io(e) => write!(f, "{e}"),
+			Self::EnableFailed(e) => write!(f, "failed to restore the terminal mode: {e}"),
+		}
+	}
+}
+
+impl std::error::Error for PromptError {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			Self::Io(e) => Some(e),
+			Self::EnableFailed(e) => Some(e),
+		}
+	}
+}
+
+impl From<std::io::Error> for PromptError {
+	fn from(error: std::io::Error) -> Self {
+		Self::Io(error)
+	}
+}
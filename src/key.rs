@@ -0,0 +1,44 @@
+/// A single key press read from the terminal.
+///
+/// Returned by [`Terminal::read_key()`][crate::Terminal::read_key] when the terminal is in raw mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+	/// A printable character.
+	Char(char),
+
+	/// A character typed while holding `Ctrl`, such as `Ctrl('c')` for `Ctrl-C`.
+	Ctrl(char),
+
+	/// The `Enter` (or `Return`) key.
+	Enter,
+
+	/// The `Backspace` key.
+	Backspace,
+
+	/// The `Escape` key.
+	Esc,
+
+	/// The `Tab` key.
+	Tab,
+
+	/// The `Up` arrow key.
+	Up,
+
+	/// The `Down` arrow key.
+	Down,
+
+	/// The `Left` arrow key.
+	Left,
+
+	/// The `Right` arrow key.
+	Right,
+
+	/// The `Home` key.
+	Home,
+
+	/// The `End` key.
+	End,
+
+	/// The `Delete` key (forward delete).
+	Delete,
+}
@@ -7,11 +7,12 @@
 //! # Example
 //! Read a username and password from the terminal:
 //! ```no_run
-//! # fn main() -> std::io::Result<()> {
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! use terminal_prompt::Terminal;
 //! let mut terminal = Terminal::open()?;
 //! let username = terminal.prompt("Username: ")?;
 //! let password = terminal.prompt_sensitive("Password: ")?;
+//! # let _ = (username, password);
 //! # Ok(())
 //! # }
 //! ```
@@ -20,8 +21,18 @@
 
 use std::io::{BufReader, BufRead, Read, Write};
 
+mod error;
+mod key;
+mod secret;
 mod sys;
 
+/// How long to wait for the bytes following an `0x1b` before treating it as a bare `Esc`.
+const ESCAPE_TIMEOUT: std::time::Duration = std::time::Duration::from_millis(50);
+
+pub use error::PromptError;
+pub use key::Key;
+pub use secret::Secret;
+
 /// A handle to the terminal associated with the current process.
 ///
 /// Once opened, you can use [`Self::prompt()`] to read non-sensitive data from the terminal,
@@ -42,6 +53,35 @@ pub struct Terminal {
 
 	/// The mode of the terminal when we opened it.
 	initial_mode: sys::TerminalMode,
+
+	/// Bytes of a partially read line, kept between calls to [`Self::read_line_timeout()`].
+	pending: Vec<u8>,
+}
+
+/// The kind of target a [`Terminal`] is connected to.
+///
+/// Returned by [`Terminal::kind()`]. Only [`TermFamily::UnixTty`] and [`TermFamily::WindowsConsole`]
+/// are real terminals that support mode changes like echo toggling and raw mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TermFamily {
+	/// A Unix terminal device (a tty).
+	UnixTty,
+
+	/// A Windows console.
+	WindowsConsole,
+
+	/// A regular file.
+	File,
+
+	/// A pipe, socket or other redirected stream that is not a terminal.
+	Redirected,
+}
+
+impl TermFamily {
+	/// Check whether this is a real terminal that supports mode changes.
+	pub fn is_terminal(self) -> bool {
+		matches!(self, Self::UnixTty | Self::WindowsConsole)
+	}
 }
 
 impl Terminal {
@@ -58,21 +98,72 @@ impl Terminal {
 	///
 	/// In all cases, if the function fails to find a terminal for the process, an error is returned.
 	pub fn open() -> std::io::Result<Self> {
-		// Open the terminal and retrieve the initial mode.
-		let terminal = sys::Terminal::open()?;
-		let initial_mode = terminal.get_terminal_mode()?;
+		Self::from_sys(sys::Terminal::open()?)
+	}
 
-		// Enable line editing mode.
-		let mut mode = initial_mode;
-		mode.enable_line_editing();
-		terminal.set_terminal_mode(&mode)?;
+	/// Open a terminal from an explicit pair of file descriptors.
+	///
+	/// Unlike [`Self::open()`], which discovers the controlling terminal, this lets you drive prompts
+	/// over an arbitrary read/write pair, such as the two ends of a pty, a forwarded socket, or an
+	/// in-memory fake in tests. The descriptors are taken by ownership and closed when the terminal
+	/// is dropped.
+	///
+	/// If the read descriptor is not a real terminal (see [`Self::kind()`]), the terminal mode is left
+	/// untouched when opening. Methods that query or change the mode, such as echo toggling,
+	/// return an error for such a target.
+	#[cfg(unix)]
+	pub fn from_fd_pair(
+		read: impl Into<std::os::unix::io::OwnedFd>,
+		write: impl Into<std::os::unix::io::OwnedFd>,
+	) -> std::io::Result<Self> {
+		Self::from_sys(sys::Terminal::from_fd_pair(read.into(), write.into())?)
+	}
+
+	/// Open a terminal from an explicit pair of handles.
+	///
+	/// Unlike [`Self::open()`], which discovers the console, this lets you drive prompts over an
+	/// arbitrary input/output pair. The handles are taken by ownership and closed when the terminal
+	/// is dropped.
+	///
+	/// If the input handle is not a real console (see [`Self::kind()`]), the terminal mode is left
+	/// untouched when opening. Methods that query or change the mode, such as echo toggling,
+	/// return an error for such a target.
+	#[cfg(windows)]
+	pub fn from_handles(
+		input: impl Into<std::os::windows::io::OwnedHandle>,
+		output: impl Into<std::os::windows::io::OwnedHandle>,
+	) -> std::io::Result<Self> {
+		Self::from_sys(sys::Terminal::from_handles(input.into(), output.into())?)
+	}
+
+	/// Build a [`Terminal`] from a platform handle, enabling line editing if it is a real terminal.
+	fn from_sys(terminal: sys::Terminal) -> std::io::Result<Self> {
+		// Only real terminals have a mode to query and adjust; for files and pipes we leave it alone.
+		let initial_mode = if terminal.kind().is_terminal() {
+			let initial_mode = terminal.get_terminal_mode()?;
+			let mut mode = initial_mode;
+			mode.enable_line_editing();
+			terminal.set_terminal_mode(&mode)?;
+			initial_mode
+		} else {
+			sys::TerminalMode::default()
+		};
 
 		Ok(Self {
 			terminal: BufReader::new(terminal),
 			initial_mode,
+			pending: Vec::new(),
 		})
 	}
 
+	/// Query what kind of target this terminal is connected to.
+	///
+	/// This is useful to detect when the target is not a real terminal, so callers can adjust their
+	/// behavior (for example by skipping echo toggling or masked rendering).
+	pub fn kind(&self) -> TermFamily {
+		self.terminal.get_ref().kind()
+	}
+
 	/// Check if the terminal is echoing input.
 	///
 	/// If enabled, any text typed on the terminal will be visible.
@@ -102,6 +193,160 @@ impl Terminal {
 		Ok(())
 	}
 
+	/// Put the terminal into raw mode.
+	///
+	/// In raw mode, input is delivered one keystroke at a time without line editing, echoing or
+	/// signal generation, which is what [`Self::read_key()`] needs to see individual key presses.
+	///
+	/// Prefer [`Self::raw_mode()`] when you want the previous mode restored automatically.
+	pub fn enable_raw_mode(&self) -> std::io::Result<()> {
+		let mut mode = self.terminal.get_ref().get_terminal_mode()?;
+		mode.enable_raw_mode();
+		self.terminal.get_ref().set_terminal_mode(&mode)?;
+		Ok(())
+	}
+
+	/// Restore the terminal to line editing mode after [`Self::enable_raw_mode()`].
+	pub fn disable_raw_mode(&self) -> std::io::Result<()> {
+		let mut mode = self.initial_mode;
+		mode.enable_line_editing();
+		self.terminal.get_ref().set_terminal_mode(&mode)?;
+		Ok(())
+	}
+
+	/// Enter raw mode and return a guard that restores the previous mode when dropped.
+	///
+	/// The returned [`RawModeGuard`] dereferences to the [`Terminal`], so you can call
+	/// [`Self::read_key()`] and other methods on it directly:
+	/// ```no_run
+	/// # fn main() -> std::io::Result<()> {
+	/// # use terminal_prompt::Terminal;
+	/// let mut terminal = Terminal::open()?;
+	/// let mut raw = terminal.raw_mode()?;
+	/// let key = raw.read_key()?;
+	/// # let _ = key;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn raw_mode(&mut self) -> std::io::Result<RawModeGuard<'_>> {
+		let previous = self.terminal.get_ref().get_terminal_mode()?;
+		let mut mode = previous;
+		mode.enable_raw_mode();
+		self.terminal.get_ref().set_terminal_mode(&mode)?;
+		Ok(RawModeGuard { terminal: self, previous })
+	}
+
+	/// Read a single key press from the terminal.
+	///
+	/// The terminal must be in raw mode (see [`Self::raw_mode()`]) for this to see individual keys
+	/// rather than whole lines. Escape sequences for the arrow keys, `Home`/`End` and `Delete` are
+	/// decoded into the matching [`Key`] variants.
+	pub fn read_key(&mut self) -> std::io::Result<Key> {
+		let byte = match self.read_raw_byte()? {
+			Some(byte) => byte,
+			None => return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof)),
+		};
+		match byte {
+			b'\r' | b'\n' => Ok(Key::Enter),
+			b'\t' => Ok(Key::Tab),
+			0x7f | 0x08 => Ok(Key::Backspace),
+			0x1b => self.read_escape_sequence(),
+			0x01..=0x1a => Ok(Key::Ctrl((byte - 1 + b'a') as char)),
+			_ => self.read_utf8_char(byte),
+		}
+	}
+
+	/// Decode a CSI escape sequence after the leading `0x1b` byte has been read.
+	///
+	/// A bare escape (nothing more to read) is reported as [`Key::Esc`]; unrecognized sequences
+	/// also fall back to [`Key::Esc`] after consuming the bytes we peeked at.
+	fn read_escape_sequence(&mut self) -> std::io::Result<Key> {
+		// The bytes of an escape sequence arrive in a single burst, so if nothing follows the
+		// `0x1b` within a short window it was a bare `Esc` press rather than the start of a sequence.
+		let introducer = match self.read_raw_byte_timeout(ESCAPE_TIMEOUT)? {
+			Some(byte) => byte,
+			None => return Ok(Key::Esc),
+		};
+		if introducer != b'[' && introducer != b'O' {
+			return Ok(Key::Esc);
+		}
+		let byte = match self.read_raw_byte_timeout(ESCAPE_TIMEOUT)? {
+			Some(byte) => byte,
+			None => return Ok(Key::Esc),
+		};
+		match byte {
+			b'A' => Ok(Key::Up),
+			b'B' => Ok(Key::Down),
+			b'C' => Ok(Key::Right),
+			b'D' => Ok(Key::Left),
+			b'H' => Ok(Key::Home),
+			b'F' => Ok(Key::End),
+			b'0'..=b'9' => {
+				// Numeric sequences carry one or more digits terminated by `~`, e.g. `[3~` or `[15~`.
+				let mut value = u32::from(byte - b'0');
+				loop {
+					match self.read_raw_byte_timeout(ESCAPE_TIMEOUT)? {
+						Some(digit @ b'0'..=b'9') => {
+							value = value.saturating_mul(10).saturating_add(u32::from(digit - b'0'));
+						}
+						Some(b'~') => break,
+						_ => return Ok(Key::Esc),
+					}
+				}
+				match value {
+					1 | 7 => Ok(Key::Home),
+					3 => Ok(Key::Delete),
+					4 | 8 => Ok(Key::End),
+					_ => Ok(Key::Esc),
+				}
+			}
+			_ => Ok(Key::Esc),
+		}
+	}
+
+	/// Decode a (possibly multi-byte) UTF-8 character whose first byte has already been read.
+	fn read_utf8_char(&mut self, first: u8) -> std::io::Result<Key> {
+		let len = match first {
+			0x00..=0x7f => 1,
+			0xc0..=0xdf => 2,
+			0xe0..=0xef => 3,
+			0xf0..=0xf7 => 4,
+			_ => return Err(std::io::Error::new(std::io::ErrorKind::InvalidData, "invalid UTF-8 start byte")),
+		};
+		let mut buffer = [0u8; 4];
+		buffer[0] = first;
+		for slot in buffer.iter_mut().take(len).skip(1) {
+			match self.read_raw_byte()? {
+				Some(byte) => *slot = byte,
+				None => return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof)),
+			}
+		}
+		match std::str::from_utf8(&buffer[..len]) {
+			Ok(text) => Ok(Key::Char(text.chars().next().unwrap())),
+			Err(_) => Err(std::io::Error::new(std::io::ErrorKind::InvalidData, "invalid UTF-8 sequence")),
+		}
+	}
+
+	/// Read a single byte directly from the terminal, bypassing the line buffer.
+	///
+	/// Returns `None` on end of file.
+	fn read_raw_byte(&mut self) -> std::io::Result<Option<u8>> {
+		let mut buffer = [0u8; 1];
+		let read = self.terminal.get_mut().read(&mut buffer)?;
+		Ok((read != 0).then_some(buffer[0]))
+	}
+
+	/// Read a single byte, giving up if none arrives within `timeout`.
+	///
+	/// Returns `None` on timeout or end of file. This is used to tell a bare `Esc` apart from the
+	/// start of an escape sequence, whose remaining bytes always follow immediately.
+	fn read_raw_byte_timeout(&mut self, timeout: std::time::Duration) -> std::io::Result<Option<u8>> {
+		if !self.terminal.get_ref().poll_readable(timeout)? {
+			return Ok(None);
+		}
+		self.read_raw_byte()
+	}
+
 	/// Read a line of input from the terminal.
 	///
 	/// If echoing is disabled, this will also print a newline character to visually indicate to the user.
@@ -128,13 +373,81 @@ impl Terminal {
 		self.read_input_line()
 	}
 
+	/// Prompt the user on the terminal, giving up after `timeout`.
+	///
+	/// Returns `Ok(None)` if no complete line was entered before the timeout expired. This is useful
+	/// for CI wrappers and prompts that fall back to a default value when left unanswered.
+	///
+	/// Like [`Self::prompt()`], this does not toggle echoing and should not be used for passwords.
+	pub fn prompt_timeout(&mut self, prompt: impl std::fmt::Display, timeout: std::time::Duration) -> std::io::Result<Option<String>> {
+		write!(self, "{prompt}")?;
+		self.flush()?;
+		self.read_line_timeout(timeout)
+	}
+
+	/// Read a line of input from the terminal, giving up after `timeout`.
+	///
+	/// Returns `Ok(None)` if no complete line arrives before the deadline. Any bytes that were typed
+	/// before the timeout are kept buffered, so a later call can resume reading the same line.
+	pub fn read_line_timeout(&mut self, timeout: std::time::Duration) -> std::io::Result<Option<String>> {
+		let deadline = std::time::Instant::now() + timeout;
+
+		loop {
+			// If we already buffered a complete line, return it straight away.
+			if let Some(pos) = self.pending.iter().position(|&b| b == b'\n') {
+				let mut line: Vec<u8> = self.pending.drain(..=pos).collect();
+				line.pop();
+				return Ok(Some(finish_line(line)?));
+			}
+
+			// Move any bytes already sitting in the reader into `pending` before polling.
+			// A previous `prompt()`/`read_line()` (or a multi-line chunk) can leave read-ahead
+			// buffered in userspace; the kernel fd would never signal for it, so polling first
+			// would strand an already-complete line and report a false timeout.
+			if !self.terminal.buffer().is_empty() {
+				self.drain_buffered();
+				continue;
+			}
+
+			let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+			if remaining.is_zero() || !self.terminal.get_ref().poll_readable(remaining)? {
+				return Ok(None);
+			}
+
+			// Input is ready; pull the next chunk into the reader, then loop to drain it.
+			if self.terminal.fill_buf()?.is_empty() {
+				// End of file: flush whatever was typed so far, if anything.
+				if self.pending.is_empty() {
+					return Ok(None);
+				}
+				let line = std::mem::take(&mut self.pending);
+				return Ok(Some(finish_line(line)?));
+			}
+		}
+	}
+
+	/// Move every byte currently buffered in the reader into `pending`, without blocking.
+	fn drain_buffered(&mut self) {
+		let consumed = {
+			let buffered = self.terminal.buffer();
+			self.pending.extend_from_slice(buffered);
+			buffered.len()
+		};
+		self.terminal.consume(consumed);
+	}
+
 	/// Prompt the user for sensitive data (like passwords) on the terminal.
 	///
 	/// This function makes sure that echoing is disabled before the prompt is shown.
 	/// If echoing was enabled, it is re-enabled after the response is read.
 	///
-	/// Use [`Self::prompt()`] to read non-sensitive data.
-	pub fn prompt_sensitive(&mut self, prompt: impl std::fmt::Display) -> std::io::Result<String> {
+	/// The response is returned as a [`Secret`], which scrubs its backing memory when dropped.
+	/// Use [`Self::prompt_sensitive_string()`] if you need a plain [`String`] instead,
+	/// and [`Self::prompt()`] to read non-sensitive data.
+	///
+	/// If the response is read but the terminal mode can not be restored afterwards, this returns
+	/// [`PromptError::EnableFailed`] so the caller can detect the broken terminal.
+	pub fn prompt_sensitive(&mut self, prompt: impl std::fmt::Display) -> Result<Secret, PromptError> {
 		let old_mode = self.terminal.get_ref().get_terminal_mode()?;
 		if old_mode.is_echo_enabled() {
 			let mut new_mode = old_mode;
@@ -142,11 +455,152 @@ impl Terminal {
 			self.terminal.get_ref().set_terminal_mode(&new_mode)?;
 		}
 		write!(self, "{prompt}")?;
-		let line = self.read_input_line();
+		let line = self.read_sensitive_line()?;
 		if old_mode.is_echo_enabled() {
-			self.terminal.get_ref().set_terminal_mode(&old_mode).ok();
+			self.terminal.get_ref().set_terminal_mode(&old_mode).map_err(PromptError::EnableFailed)?;
 		}
-		line
+		Ok(line)
+	}
+
+	/// Prompt the user for sensitive data (like passwords) and return it as a plain [`String`].
+	///
+	/// This behaves exactly like [`Self::prompt_sensitive()`] but returns an ordinary [`String`]
+	/// that is *not* scrubbed from memory when dropped.
+	/// Prefer [`Self::prompt_sensitive()`] unless you specifically need an owned `String`.
+	pub fn prompt_sensitive_string(&mut self, prompt: impl std::fmt::Display) -> Result<String, PromptError> {
+		let old_mode = self.terminal.get_ref().get_terminal_mode()?;
+		if old_mode.is_echo_enabled() {
+			let mut new_mode = old_mode;
+			new_mode.disable_echo();
+			self.terminal.get_ref().set_terminal_mode(&new_mode)?;
+		}
+		write!(self, "{prompt}")?;
+		let line = self.read_input_line()?;
+		if old_mode.is_echo_enabled() {
+			self.terminal.get_ref().set_terminal_mode(&old_mode).map_err(PromptError::EnableFailed)?;
+		}
+		Ok(line)
+	}
+
+	/// Prompt the user for sensitive data, showing a mask glyph for each typed character.
+	///
+	/// Unlike [`Self::prompt_sensitive()`], which relies on the terminal not echoing the input at all,
+	/// this puts the terminal into a character-at-a-time mode and renders the feedback itself.
+	/// For every accepted character the `mask` glyph is written to the terminal (or nothing if `mask`
+	/// is `None`), and `Backspace` erases the last mask and drops the last character from the response.
+	///
+	/// `Enter` terminates the line. `Ctrl-C` and `Ctrl-D` abort the prompt with a
+	/// [`PromptError::Io`] error: `Ctrl-C` yields [`ErrorKind::Interrupted`][std::io::ErrorKind::Interrupted]
+	/// and `Ctrl-D` yields [`ErrorKind::UnexpectedEof`][std::io::ErrorKind::UnexpectedEof].
+	pub fn prompt_masked(&mut self, prompt: impl std::fmt::Display, mask: Option<char>) -> Result<Secret, PromptError> {
+		write!(self, "{prompt}")?;
+		self.flush()?;
+
+		let old_mode = self.terminal.get_ref().get_terminal_mode()?;
+		let mut raw_mode = old_mode;
+		raw_mode.enable_raw_mode();
+		self.terminal.get_ref().set_terminal_mode(&raw_mode)?;
+
+		let result = self.read_masked_line(mask);
+		let restored = self.terminal.get_ref().set_terminal_mode(&old_mode);
+
+		let line = result?;
+		restored.map_err(PromptError::EnableFailed)?;
+		Ok(line)
+	}
+
+	/// Read a masked line while the terminal is in character-at-a-time mode.
+	///
+	/// The collected bytes are scrubbed on every error path before the error is returned.
+	fn read_masked_line(&mut self, mask: Option<char>) -> std::io::Result<Secret> {
+		let mut bytes = Vec::new();
+		let mut char_lengths = Vec::new();
+		loop {
+			let key = match self.read_key() {
+				Ok(key) => key,
+				Err(e) => {
+					secret::zero(&mut bytes);
+					return Err(e);
+				}
+			};
+			match key {
+				Key::Enter => {
+					write!(self, "\r\n").ok();
+					self.flush().ok();
+					break;
+				}
+				Key::Backspace => {
+					if let Some(length) = char_lengths.pop() {
+						bytes.truncate(bytes.len() - length);
+						if mask.is_some() {
+							write!(self, "\x08 \x08").ok();
+							self.flush().ok();
+						}
+					}
+				}
+				Key::Ctrl('c') => {
+					secret::zero(&mut bytes);
+					return Err(std::io::Error::new(std::io::ErrorKind::Interrupted, "prompt interrupted"));
+				}
+				Key::Ctrl('d') => {
+					secret::zero(&mut bytes);
+					return Err(std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "end of input"));
+				}
+				Key::Char(c) => {
+					let mut buffer = [0u8; 4];
+					let encoded = c.encode_utf8(&mut buffer);
+					bytes.extend_from_slice(encoded.as_bytes());
+					char_lengths.push(encoded.len());
+					if let Some(mask) = mask {
+						write!(self, "{mask}").ok();
+						self.flush().ok();
+					}
+				}
+				_ => {}
+			}
+		}
+
+		Secret::from_utf8(bytes)
+	}
+
+	/// Read a line of sensitive input from the terminal into a [`Secret`].
+	///
+	/// Unlike [`Self::read_input_line()`], this reads directly from the underlying terminal
+	/// rather than through the buffered reader, so the typed line never lingers in the
+	/// [`BufReader`]'s internal buffer. Any scratch buffer used while reading is scrubbed
+	/// with [`secret::zero()`] before it goes out of scope.
+	fn read_sensitive_line(&mut self) -> std::io::Result<Secret> {
+		let mut bytes = Vec::new();
+		let mut scratch = [0u8; 64];
+		loop {
+			let read = self.terminal.get_mut().read(&mut scratch);
+			let n = match read {
+				Ok(n) => n,
+				Err(e) => {
+					secret::zero(&mut scratch);
+					return Err(e);
+				}
+			};
+			if n == 0 {
+				break;
+			}
+			if let Some(pos) = scratch[..n].iter().position(|&b| b == b'\n') {
+				bytes.extend_from_slice(&scratch[..pos]);
+				secret::zero(&mut scratch);
+				break;
+			}
+			bytes.extend_from_slice(&scratch[..n]);
+			secret::zero(&mut scratch);
+		}
+
+		if bytes.last() == Some(&b'\r') {
+			bytes.pop();
+		}
+
+		// Echoing was disabled, so emit a newline to visually acknowledge the input.
+		writeln!(self).ok();
+
+		Secret::from_utf8(bytes)
 	}
 }
 
@@ -156,6 +610,50 @@ impl Drop for Terminal {
 	}
 }
 
+/// Turn a line of raw bytes (without the trailing newline) into a [`String`].
+///
+/// A trailing carriage return is stripped so `\r\n` line endings are handled transparently.
+fn finish_line(mut bytes: Vec<u8>) -> std::io::Result<String> {
+	if bytes.last() == Some(&b'\r') {
+		bytes.pop();
+	}
+	String::from_utf8(bytes)
+		.map_err(|_| std::io::Error::new(std::io::ErrorKind::InvalidData, "input was not valid UTF-8"))
+}
+
+/// An RAII guard that keeps the terminal in raw mode for its lifetime.
+///
+/// Created by [`Terminal::raw_mode()`]. When the guard is dropped, the terminal mode that was
+/// active when the guard was created is restored. The guard dereferences to the underlying
+/// [`Terminal`], so you can call methods like [`Terminal::read_key()`] directly on it.
+pub struct RawModeGuard<'a> {
+	/// The terminal that was put into raw mode.
+	terminal: &'a mut Terminal,
+
+	/// The terminal mode that was active before raw mode was enabled.
+	previous: sys::TerminalMode,
+}
+
+impl Drop for RawModeGuard<'_> {
+	fn drop(&mut self) {
+		self.terminal.terminal.get_ref().set_terminal_mode(&self.previous).ok();
+	}
+}
+
+impl std::ops::Deref for RawModeGuard<'_> {
+	type Target = Terminal;
+
+	fn deref(&self) -> &Terminal {
+		self.terminal
+	}
+}
+
+impl std::ops::DerefMut for RawModeGuard<'_> {
+	fn deref_mut(&mut self) -> &mut Terminal {
+		self.terminal
+	}
+}
+
 impl Read for Terminal {
 	fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
 		self.terminal.read(buf)
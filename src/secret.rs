@@ -0,0 +1,81 @@
+use std::ops::Deref;
+
+/// A buffer holding sensitive data that is scrubbed from memory when dropped.
+///
+/// This is returned by [`Terminal::prompt_sensitive()`][crate::Terminal::prompt_sensitive]
+/// so that a typed password does not linger in the heap after it is no longer needed.
+/// The backing bytes are overwritten with zeros before they are deallocated.
+///
+/// `Secret` deliberately does not implement [`Clone`], so a secret can not be duplicated by accident.
+/// Its [`Debug`][std::fmt::Debug] implementation is redacted for the same reason.
+pub struct Secret {
+	/// The sensitive data.
+	inner: String,
+}
+
+impl Secret {
+	/// Create a secret from a byte buffer, validating that it contains UTF-8.
+	///
+	/// The input buffer is consumed so that the only remaining copy of the data lives inside the returned secret.
+	pub(crate) fn from_utf8(bytes: Vec<u8>) -> std::io::Result<Self> {
+		match String::from_utf8(bytes) {
+			Ok(inner) => Ok(Self { inner }),
+			Err(e) => {
+				// Scrub the bytes before we drop them on the error path too.
+				let mut bytes = e.into_bytes();
+				zero(&mut bytes);
+				Err(std::io::Error::new(std::io::ErrorKind::InvalidData, "input was not valid UTF-8"))
+			}
+		}
+	}
+
+	/// Get the secret as a string slice.
+	pub fn as_str(&self) -> &str {
+		&self.inner
+	}
+
+	/// Get the secret as a byte slice.
+	pub fn as_bytes(&self) -> &[u8] {
+		self.inner.as_bytes()
+	}
+}
+
+impl Deref for Secret {
+	type Target = str;
+
+	fn deref(&self) -> &str {
+		&self.inner
+	}
+}
+
+impl Drop for Secret {
+	fn drop(&mut self) {
+		// SAFETY: we only write zeros into the buffer, which is always valid UTF-8,
+		// and we truncate the length afterwards so the scrubbed bytes are never read as a string.
+		unsafe {
+			let bytes = self.inner.as_mut_vec();
+			zero(bytes);
+			bytes.set_len(0);
+		}
+	}
+}
+
+impl std::fmt::Debug for Secret {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_tuple("Secret").field(&"<redacted>").finish()
+	}
+}
+
+/// Overwrite a byte buffer with zeros in a way the compiler can not optimize away.
+///
+/// The scrub is performed with [`std::ptr::write_volatile`] so that a dead-store elimination pass
+/// can not remove it just because the buffer is about to be dropped.
+/// This is the same trick that `rpassword` uses for its `zero_on_drop` behavior.
+pub(crate) fn zero(buf: &mut [u8]) {
+	for byte in buf.iter_mut() {
+		unsafe {
+			std::ptr::write_volatile(byte, 0);
+		}
+	}
+	std::sync::atomic::compiler_fence(std::sync::atomic::Ordering::SeqCst);
+}
@@ -1,14 +1,31 @@
 use std::fs::File;
 use std::io::{Read, Write};
 use std::mem::ManuallyDrop;
-use std::os::unix::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, RawFd};
+use std::os::unix::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, OwnedFd, RawFd};
 
-/// Unix handle to an open terminal.
-pub enum Terminal {
-	/// Non-owning file for one of the standard I/O streams.
+use crate::TermFamily;
+
+/// Unix handle to an open terminal, with separate read and write endpoints.
+///
+/// For the controlling terminal both endpoints refer to the same underlying file,
+/// but [`Terminal::from_fd_pair()`] allows them to be distinct (for example the two ends of a pty).
+pub struct Terminal {
+	/// The endpoint we read input from.
+	read: Endpoint,
+
+	/// The endpoint we write output to.
+	write: Endpoint,
+
+	/// The kind of target the endpoints refer to.
+	kind: TermFamily,
+}
+
+/// One end of a [`Terminal`].
+enum Endpoint {
+	/// Non-owning file for one of the standard I/O streams or another borrowed descriptor.
 	Stdio(ManuallyDrop<File>),
 
-	/// Owned file for `/dev/tty`.
+	/// Owned file, such as `/dev/tty` or a descriptor handed to [`Terminal::from_fd_pair()`].
 	File(File),
 }
 
@@ -17,6 +34,16 @@ pub struct TerminalMode {
 	termios: libc::termios,
 }
 
+impl Default for TerminalMode {
+	fn default() -> Self {
+		// A zeroed `termios` is only used as a placeholder for non-terminal targets.
+		// Applying it with `tcsetattr` fails there, because the descriptor is not a terminal.
+		Self {
+			termios: unsafe { std::mem::zeroed() },
+		}
+	}
+}
+
 impl Terminal {
 	pub fn open() -> std::io::Result<Self> {
 		if let Some(terminal) = open_fd_terminal(2) {
@@ -31,13 +58,31 @@ impl Terminal {
 				.write(true)
 				.open("/dev/tty")?;
 			if is_terminal(file.as_fd()) {
-				Ok(Self::File(file))
+				let write = file.try_clone()?;
+				Ok(Self {
+					read: Endpoint::File(file),
+					write: Endpoint::File(write),
+					kind: TermFamily::UnixTty,
+				})
 			} else {
 				Err(std::io::Error::from_raw_os_error(libc::ENOTTY))
 			}
 		}
 	}
 
+	pub fn from_fd_pair(read: OwnedFd, write: OwnedFd) -> std::io::Result<Self> {
+		let kind = classify(read.as_fd());
+		Ok(Self {
+			read: Endpoint::File(File::from(read)),
+			write: Endpoint::File(File::from(write)),
+			kind,
+		})
+	}
+
+	pub fn kind(&self) -> TermFamily {
+		self.kind
+	}
+
 	pub fn get_terminal_mode(&self) -> std::io::Result<TerminalMode> {
 		unsafe {
 			let mut termios = std::mem::zeroed();
@@ -57,6 +102,26 @@ impl Terminal {
 		}
 	}
 
+	/// Wait until the terminal is readable or the timeout expires.
+	///
+	/// Returns `Ok(true)` if there is input ready to be read, or `Ok(false)` if the timeout expired first.
+	pub fn poll_readable(&self, timeout: std::time::Duration) -> std::io::Result<bool> {
+		let mut fd = libc::pollfd {
+			fd: self.as_fd().as_raw_fd(),
+			events: libc::POLLIN,
+			revents: 0,
+		};
+		let millis = timeout.as_millis().min(i32::MAX as u128) as i32;
+		let ret = unsafe { libc::poll(&mut fd, 1, millis) };
+		if ret < 0 {
+			Err(std::io::Error::last_os_error())
+		} else {
+			Ok(ret > 0 && fd.revents & libc::POLLIN != 0)
+		}
+	}
+}
+
+impl Endpoint {
 	fn as_file(&self) -> &File {
 		match self {
 			Self::Stdio(io) => io,
@@ -68,7 +133,13 @@ impl Terminal {
 fn open_fd_terminal(fd: RawFd) -> Option<Terminal> {
 	let file = unsafe { ManuallyDrop::new(File::from_raw_fd(fd)) };
 	if is_terminal(file.as_fd()) {
-		Some(Terminal::Stdio(file))
+		// The same descriptor is used for both reading and writing.
+		let write = unsafe { ManuallyDrop::new(File::from_raw_fd(fd)) };
+		Some(Terminal {
+			read: Endpoint::Stdio(file),
+			write: Endpoint::Stdio(write),
+			kind: TermFamily::UnixTty,
+		})
 	} else {
 		None
 	}
@@ -92,6 +163,18 @@ impl TerminalMode {
 	pub fn is_echo_enabled(&self) -> bool {
 		self.termios.c_lflag & libc::ECHO != 0
 	}
+
+	pub fn enable_raw_mode(&mut self) {
+		// Equivalent to `cfmakeraw()`: disable canonical mode, echoing, signal generation and
+		// extended input processing, turn off input/output translation and flow control, force
+		// 8-bit characters, and read one byte at a time with no inter-byte timeout.
+		self.termios.c_lflag &= !(libc::ICANON | libc::ECHO | libc::ISIG | libc::IEXTEN);
+		self.termios.c_iflag &= !(libc::IXON | libc::ICRNL | libc::BRKINT | libc::INPCK | libc::ISTRIP);
+		self.termios.c_oflag &= !libc::OPOST;
+		self.termios.c_cflag |= libc::CS8;
+		self.termios.c_cc[libc::VMIN] = 1;
+		self.termios.c_cc[libc::VTIME] = 0;
+	}
 }
 
 fn is_terminal(fd: BorrowedFd) -> bool {
@@ -100,6 +183,27 @@ fn is_terminal(fd: BorrowedFd) -> bool {
 	}
 }
 
+/// Determine what kind of target a descriptor refers to.
+fn classify(fd: BorrowedFd) -> TermFamily {
+	if is_terminal(fd) {
+		TermFamily::UnixTty
+	} else if is_regular_file(fd) {
+		TermFamily::File
+	} else {
+		TermFamily::Redirected
+	}
+}
+
+fn is_regular_file(fd: BorrowedFd) -> bool {
+	unsafe {
+		let mut stat = std::mem::zeroed();
+		if libc::fstat(fd.as_raw_fd(), &mut stat) != 0 {
+			return false;
+		}
+		stat.st_mode & libc::S_IFMT == libc::S_IFREG
+	}
+}
+
 fn check_ret(input: i32) -> std::io::Result<()> {
 	if input == 0 {
 		Ok(())
@@ -110,33 +214,30 @@ fn check_ret(input: i32) -> std::io::Result<()> {
 
 impl AsFd for Terminal {
 	fn as_fd(&self) -> std::os::fd::BorrowedFd<'_> {
-		match self {
-			Self::Stdio(stdin) => stdin.as_fd(),
-			Self::File(file) => file.as_fd(),
-		}
+		self.read.as_file().as_fd()
 	}
 }
 
 impl Read for Terminal {
 	fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
-		self.as_file().read(buf)
+		self.read.as_file().read(buf)
 	}
 
 	fn read_vectored(&mut self, bufs: &mut [std::io::IoSliceMut<'_>]) -> std::io::Result<usize> {
-		self.as_file().read_vectored(bufs)
+		self.read.as_file().read_vectored(bufs)
 	}
 }
 
 impl Write for Terminal {
 	fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
-		self.as_file().write(buf)
+		self.write.as_file().write(buf)
 	}
 
 	fn flush(&mut self) -> std::io::Result<()> {
-		self.as_file().flush()
+		self.write.as_file().flush()
 	}
 
 	fn write_vectored(&mut self, bufs: &[std::io::IoSlice<'_>]) -> std::io::Result<usize> {
-		self.as_file().write_vectored(bufs)
+		self.write.as_file().write_vectored(bufs)
 	}
 }
@@ -1,23 +1,49 @@
+use std::fs::File;
 use std::io::{Read, Write};
-use std::os::windows::io::{AsHandle, AsRawHandle, BorrowedHandle};
+use std::mem::ManuallyDrop;
+use std::os::windows::io::{AsHandle, AsRawHandle, BorrowedHandle, FromRawHandle, OwnedHandle};
 
 use winapi::um::consoleapi::{
 	GetConsoleMode,
 	SetConsoleMode,
 };
+use winapi::um::fileapi::GetFileType;
+use winapi::um::winbase::FILE_TYPE_DISK;
 use winapi::um::wincon::{
 	ENABLE_LINE_INPUT,
 	ENABLE_ECHO_INPUT,
+	ENABLE_PROCESSED_INPUT,
 };
+use winapi::um::synchapi::WaitForSingleObject;
+use winapi::um::winbase::{WAIT_OBJECT_0, INFINITE};
+use winapi::shared::winerror::WAIT_TIMEOUT;
 
 use winapi::shared::minwindef::{BOOL, DWORD};
 
+use crate::TermFamily;
+
+/// Windows handle to an open terminal, with separate input and output endpoints.
 pub struct Terminal {
-	input: std::io::Stdin,
-	output: std::io::Stderr,
+	/// The endpoint we read input from.
+	input: Endpoint,
+
+	/// The endpoint we write output to.
+	output: Endpoint,
+
+	/// The kind of target the endpoints refer to.
+	kind: TermFamily,
 }
 
-#[derive(Copy, Clone)]
+/// One end of a [`Terminal`].
+enum Endpoint {
+	/// Non-owning file for one of the standard I/O streams.
+	Stdio(ManuallyDrop<File>),
+
+	/// Owned file handed to [`Terminal::from_handles()`].
+	File(File),
+}
+
+#[derive(Copy, Clone, Default)]
 pub struct TerminalMode {
 	input_mode: DWORD,
 }
@@ -32,16 +58,32 @@ impl Terminal {
 		if !is_terminal(output.as_handle()) {
 			return Err(std::io::Error::new(std::io::ErrorKind::Other, "stderr is not a terminal"));
 		}
+		let input = unsafe { ManuallyDrop::new(File::from_raw_handle(input.as_raw_handle())) };
+		let output = unsafe { ManuallyDrop::new(File::from_raw_handle(output.as_raw_handle())) };
 		Ok(Self {
-			input,
-			output,
+			input: Endpoint::Stdio(input),
+			output: Endpoint::Stdio(output),
+			kind: TermFamily::WindowsConsole,
 		})
 	}
 
+	pub fn from_handles(input: OwnedHandle, output: OwnedHandle) -> std::io::Result<Self> {
+		let kind = classify(input.as_handle());
+		Ok(Self {
+			input: Endpoint::File(File::from(input)),
+			output: Endpoint::File(File::from(output)),
+			kind,
+		})
+	}
+
+	pub fn kind(&self) -> TermFamily {
+		self.kind
+	}
+
 	pub fn get_terminal_mode(&self) -> std::io::Result<TerminalMode> {
 		unsafe {
 			let mut input_mode = 0;
-			check_ret(GetConsoleMode(self.input.as_raw_handle().cast(), &mut input_mode))?;
+			check_ret(GetConsoleMode(self.input.as_file().as_raw_handle().cast(), &mut input_mode))?;
 			Ok(TerminalMode {
 				input_mode,
 			})
@@ -51,12 +93,36 @@ impl Terminal {
 	pub fn set_terminal_mode(&self, mode: &TerminalMode) -> std::io::Result<()> {
 		unsafe {
 			check_ret(SetConsoleMode(
-				self.input.as_raw_handle().cast(),
+				self.input.as_file().as_raw_handle().cast(),
 				mode.input_mode,
 			))?;
 			Ok(())
 		}
 	}
+
+	/// Wait until the terminal is readable or the timeout expires.
+	///
+	/// Returns `Ok(true)` if the input handle was signalled, or `Ok(false)` if the timeout expired first.
+	pub fn poll_readable(&self, timeout: std::time::Duration) -> std::io::Result<bool> {
+		let millis = timeout.as_millis().min((INFINITE - 1) as u128) as DWORD;
+		let ret = unsafe { WaitForSingleObject(self.input.as_file().as_raw_handle().cast(), millis) };
+		if ret == WAIT_OBJECT_0 {
+			Ok(true)
+		} else if ret == WAIT_TIMEOUT {
+			Ok(false)
+		} else {
+			Err(std::io::Error::last_os_error())
+		}
+	}
+}
+
+impl Endpoint {
+	fn as_file(&self) -> &File {
+		match self {
+			Self::Stdio(io) => io,
+			Self::File(io) => io,
+		}
+	}
 }
 
 impl TerminalMode {
@@ -75,6 +141,12 @@ impl TerminalMode {
 	pub fn is_echo_enabled(&self) -> bool {
 		self.input_mode & ENABLE_ECHO_INPUT != 0
 	}
+
+	pub fn enable_raw_mode(&mut self) {
+		// Disable line buffering, echoing and processed input (which handles things like Ctrl-C),
+		// so that keystrokes are delivered to us one at a time and unmodified.
+		self.input_mode &= !(ENABLE_LINE_INPUT | ENABLE_ECHO_INPUT | ENABLE_PROCESSED_INPUT);
+	}
 }
 
 fn is_terminal(handle: BorrowedHandle) -> bool {
@@ -84,6 +156,17 @@ fn is_terminal(handle: BorrowedHandle) -> bool {
 	}
 }
 
+/// Determine what kind of target a handle refers to.
+fn classify(handle: BorrowedHandle) -> TermFamily {
+	if is_terminal(handle) {
+		TermFamily::WindowsConsole
+	} else if unsafe { GetFileType(handle.as_raw_handle().cast()) } == FILE_TYPE_DISK {
+		TermFamily::File
+	} else {
+		TermFamily::Redirected
+	}
+}
+
 fn check_ret(input: BOOL) -> std::io::Result<()> {
 	if input != 0 {
 		Ok(())
@@ -94,24 +177,24 @@ fn check_ret(input: BOOL) -> std::io::Result<()> {
 
 impl Read for Terminal {
 	fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
-		self.input.read(buf)
+		self.input.as_file().read(buf)
 	}
 
 	fn read_vectored(&mut self, bufs: &mut [std::io::IoSliceMut<'_>]) -> std::io::Result<usize> {
-		self.input.read_vectored(bufs)
+		self.input.as_file().read_vectored(bufs)
 	}
 }
 
 impl Write for Terminal {
 	fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
-		self.output.write(buf)
+		self.output.as_file().write(buf)
 	}
 
 	fn flush(&mut self) -> std::io::Result<()> {
-		self.output.flush()
+		self.output.as_file().flush()
 	}
 
 	fn write_vectored(&mut self, bufs: &[std::io::IoSlice<'_>]) -> std::io::Result<usize> {
-		self.output.write_vectored(bufs)
+		self.output.as_file().write_vectored(bufs)
 	}
 }
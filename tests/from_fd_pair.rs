@@ -0,0 +1,34 @@
+//! Drive a prompt over an in-memory socket pair to exercise [`Terminal::from_fd_pair()`].
+#![cfg(unix)]
+
+use std::io::{Read, Write};
+use std::os::unix::io::OwnedFd;
+use std::os::unix::net::UnixStream;
+
+use terminal_prompt::{TermFamily, Terminal};
+
+#[test]
+fn prompt_round_trips_over_a_socket_pair() -> std::io::Result<()> {
+	let (terminal_side, mut test_side) = UnixStream::pair()?;
+
+	// Feed a line of input up front; it waits in the socket buffer until the prompt reads it.
+	test_side.write_all(b"hunter2\n")?;
+
+	let read = OwnedFd::from(terminal_side.try_clone()?);
+	let write = OwnedFd::from(terminal_side);
+	let mut terminal = Terminal::from_fd_pair(read, write)?;
+
+	// A socket pair is not a real terminal, so mode toggling is skipped.
+	assert_eq!(terminal.kind(), TermFamily::Redirected);
+	assert!(!terminal.kind().is_terminal());
+
+	let answer = terminal.prompt("Password: ")?;
+	assert_eq!(answer, "hunter2");
+
+	// The prompt text was written to the other end of the pair.
+	let mut written = vec![0u8; "Password: ".len()];
+	test_side.read_exact(&mut written)?;
+	assert_eq!(&written, b"Password: ");
+
+	Ok(())
+}